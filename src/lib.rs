@@ -1,5 +1,12 @@
-use cairo::Context;
+use cairo::{Context, LinearGradient, RadialGradient};
+use std::error::Error;
 
+pub mod palette;
+pub mod path;
+pub mod shadow;
+pub mod text;
+
+#[derive(Debug, Clone, Copy)]
 pub struct Color {
     c: u32,
 }
@@ -43,9 +50,58 @@ impl Color {
             alpha,
         );
     }
+
+    /// Builds a color from hue (degrees, wraps around 360), saturation, and
+    /// value (both `0.0..=1.0`).
+    pub fn from_hsv(h: f64, s: f64, v: f64) -> Self {
+        let h = h.rem_euclid(360.0);
+        let c = v * s;
+        let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+        let m = v - c;
+
+        let (r1, g1, b1) = match (h / 60.0) as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        Self::from_rgb(
+            ((r1 + m) * 255.0).round() as u8,
+            ((g1 + m) * 255.0).round() as u8,
+            ((b1 + m) * 255.0).round() as u8,
+        )
+    }
+
+    /// Returns `(hue, saturation, value)`, the inverse of [`Color::from_hsv`].
+    pub fn to_hsv(&self) -> (f64, f64, f64) {
+        let r = self.r() as f64 / 255.0;
+        let g = self.g() as f64 / 255.0;
+        let b = self.b() as f64 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+
+        (h, s, max)
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Rect {
     tl: (f64, f64),
     br: (f64, f64),
@@ -74,6 +130,200 @@ impl Rect {
             br: (self.br.0 * sx, self.br.1 * sy),
         }
     }
+
+    pub fn center(&self) -> (f64, f64) {
+        (
+            (self.tl.0 + self.br.0) / 2.0,
+            (self.tl.1 + self.br.1) / 2.0,
+        )
+    }
+
+    fn half_diagonal(&self) -> f64 {
+        (self.width() / 2.0).hypot(self.height() / 2.0)
+    }
+}
+
+impl Rect {
+    pub fn new(tl: (f64, f64), br: (f64, f64)) -> Self {
+        Self { tl, br }
+    }
+}
+
+/// A single color stop in a [`Paint::Linear`] or [`Paint::Radial`] gradient.
+#[derive(Debug, Clone, Copy)]
+pub struct Stop {
+    pub offset: f64,
+    pub color: Color,
+    pub alpha: f64,
+}
+
+impl Stop {
+    pub fn new(offset: f64, color: Color, alpha: f64) -> Self {
+        Self {
+            offset,
+            color,
+            alpha,
+        }
+    }
+}
+
+/// Something that can be installed as a `Context`'s source, the way
+/// [`Color::set`] installs a flat color.
+#[derive(Debug, Clone)]
+pub enum Paint {
+    Solid(Color),
+    Linear {
+        stops: Vec<Stop>,
+        angle: f64,
+    },
+    Radial {
+        stops: Vec<Stop>,
+        center: (f64, f64),
+        radius: f64,
+    },
+}
+
+impl Paint {
+    /// Installs this paint as `ctx`'s source, fit to `rect` in the
+    /// coordinate system currently active on `ctx`.
+    ///
+    /// `rect` gives the gradients something to measure against: a linear
+    /// gradient's endpoints are chosen so its line spans `rect` at `angle`,
+    /// and a radial gradient's `center`/`radius` are fractions of `rect`'s
+    /// half-size and half-diagonal.
+    pub fn set(&self, ctx: &Context, rect: &Rect) {
+        match self {
+            Self::Solid(color) => color.set(ctx),
+            Self::Linear { stops, angle } => {
+                let (x0, y0, x1, y1) = linear_endpoints(rect, *angle);
+                let gradient = LinearGradient::new(x0, y0, x1, y1);
+                add_stops(&gradient, stops);
+                let _ = ctx.set_source(&gradient);
+            }
+            Self::Radial {
+                stops,
+                center,
+                radius,
+            } => {
+                let (mx, my) = rect.center();
+                let cx = mx + center.0 * rect.width() / 2.0;
+                let cy = my + center.1 * rect.height() / 2.0;
+                let r = radius * rect.half_diagonal();
+                let gradient = RadialGradient::new(cx, cy, 0.0, cx, cy, r);
+                add_stops(&gradient, stops);
+                let _ = ctx.set_source(&gradient);
+            }
+        }
+    }
+}
+
+fn add_stops(gradient: &cairo::Gradient, stops: &[Stop]) {
+    for stop in stops {
+        gradient.add_color_stop_rgba(
+            stop.offset,
+            stop.color.r() as f64 / 255.0,
+            stop.color.g() as f64 / 255.0,
+            stop.color.b() as f64 / 255.0,
+            stop.alpha,
+        );
+    }
+}
+
+/// Endpoints of the gradient line spanning `rect` at `angle` degrees
+/// (0 = bottom-to-top, 90 = left-to-right), centered on `rect`.
+fn linear_endpoints(rect: &Rect, angle: f64) -> (f64, f64, f64, f64) {
+    let theta = angle.to_radians();
+    let (dx, dy) = (theta.sin(), -theta.cos());
+    let len = (rect.width() * dx.abs() + rect.height() * dy.abs()).max(1.0);
+    let (cx, cy) = rect.center();
+    (
+        cx - dx * len / 2.0,
+        cy - dy * len / 2.0,
+        cx + dx * len / 2.0,
+        cy + dy * len / 2.0,
+    )
+}
+
+impl std::str::FromStr for Paint {
+    type Err = Box<dyn Error>;
+
+    /// Parses the `--bg`/`--fg` grammar:
+    ///
+    /// - `solid:RRGGBB[@alpha]` (the alpha is parsed but ignored: solid
+    ///   paints are opaque)
+    /// - `linear:RRGGBB[@alpha]..RRGGBB[@alpha][..RRGGBB[@alpha]...][;angle]`
+    /// - `radial:RRGGBB[@alpha]..RRGGBB[@alpha][..RRGGBB[@alpha]...]`
+    ///
+    /// Stops are spaced evenly between offsets `0` and `1`; `angle` defaults
+    /// to `0` (bottom-to-top) when omitted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, rest) = s.split_once(':').ok_or("invalid paint: missing kind")?;
+        match kind {
+            "solid" => Ok(Self::Solid(parse_color(rest)?.0)),
+            "linear" => {
+                let (stops, angle) = match rest.split_once(';') {
+                    Some((stops, angle)) => (stops, angle.parse()?),
+                    None => (rest, 0.0),
+                };
+                Ok(Self::Linear {
+                    stops: parse_stops(stops)?,
+                    angle,
+                })
+            }
+            "radial" => Ok(Self::Radial {
+                stops: parse_stops(rest)?,
+                center: (0.0, 0.0),
+                radius: 1.0,
+            }),
+            _ => Err(format!("invalid paint: unknown kind '{}'", kind).into()),
+        }
+    }
+}
+
+fn parse_color(s: &str) -> Result<(Color, f64), Box<dyn Error>> {
+    let (hex, alpha) = match s.split_once('@') {
+        Some((hex, alpha)) => (hex, alpha.parse()?),
+        None => (s, 1.0),
+    };
+    Ok((Color::from_u32(u32::from_str_radix(hex, 16)?), alpha))
+}
+
+fn parse_stops(s: &str) -> Result<Vec<Stop>, Box<dyn Error>> {
+    let parts: Vec<&str> = s.split("..").collect();
+    if parts.len() < 2 {
+        return Err("invalid paint: need at least two color stops".into());
+    }
+    let n = parts.len() - 1;
+    parts
+        .into_iter()
+        .enumerate()
+        .map(|(i, part)| {
+            let (color, alpha) = parse_color(part)?;
+            Ok(Stop::new(i as f64 / n as f64, color, alpha))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hsv_round_trips_through_rgb() {
+        for c in [
+            Color::from_u32(0x236cff),
+            Color::from_u32(0xff6b35),
+            Color::from_u32(0x111111),
+            Color::from_u32(0xffffff),
+            Color::from_u32(0x000000),
+        ] {
+            let (h, s, v) = c.to_hsv();
+            let round_tripped = Color::from_hsv(h, s, v);
+            assert!((c.r() as i32 - round_tripped.r() as i32).abs() <= 1);
+            assert!((c.g() as i32 - round_tripped.g() as i32).abs() <= 1);
+            assert!((c.b() as i32 - round_tripped.b() as i32).abs() <= 1);
+        }
+    }
 }
 
 pub mod logo_a {
@@ -1,8 +1,16 @@
-use cairo::{Context, Format, ImageSurface};
+use cairo::{Context, FontSlant, FontWeight, Format, ImageSurface, PdfSurface, PsSurface, SvgSurface};
 use clap::Parser;
-use intuit_empire::{logo_a, logo_b, Color};
+use intuit_empire::path;
+use intuit_empire::shadow::Shadow;
+use intuit_empire::text::Block;
+use intuit_empire::{logo_a, logo_b, palette, Color, Paint, Rect};
 use std::error::Error;
 use std::fs;
+use std::path::Path;
+
+fn parse_paint(s: &str) -> Result<Paint, String> {
+    s.parse::<Paint>().map_err(|e| e.to_string())
+}
 
 #[derive(Debug, Clone)]
 struct Size {
@@ -38,6 +46,59 @@ impl std::fmt::Display for Size {
     }
 }
 
+/// The surface backends that can be selected by `--dst`'s file extension.
+///
+/// `.svg`, `.pdf`, and `.eps` produce resolution-independent vector output;
+/// anything else falls back to the original rasterized `ImageSurface`.
+enum Surface {
+    Image(ImageSurface),
+    Svg(SvgSurface),
+    Pdf(PdfSurface),
+    Eps(PsSurface),
+}
+
+impl Surface {
+    fn create(dst: &str, size: &Size) -> Result<Self, Box<dyn Error>> {
+        let w = size.width as f64;
+        let h = size.height as f64;
+
+        match Path::new(dst).extension().and_then(|ext| ext.to_str()) {
+            Some("svg") => Ok(Self::Svg(SvgSurface::new(w, h, Some(dst))?)),
+            Some("pdf") => Ok(Self::Pdf(PdfSurface::new(w, h, dst)?)),
+            Some("eps") => {
+                let surface = PsSurface::new(w, h, dst)?;
+                surface.set_eps(true);
+                Ok(Self::Eps(surface))
+            }
+            _ => Ok(Self::Image(ImageSurface::create(
+                Format::ARgb32,
+                size.width,
+                size.height,
+            )?)),
+        }
+    }
+
+    fn context(&self) -> Result<Context, Box<dyn Error>> {
+        let ctx = match self {
+            Self::Image(s) => Context::new(s)?,
+            Self::Svg(s) => Context::new(s)?,
+            Self::Pdf(s) => Context::new(s)?,
+            Self::Eps(s) => Context::new(s)?,
+        };
+        Ok(ctx)
+    }
+
+    fn finish(self, dst: &str) -> Result<(), Box<dyn Error>> {
+        match self {
+            Self::Image(s) => s.write_to_png(&mut fs::File::create(dst)?)?,
+            Self::Svg(s) => s.finish(),
+            Self::Pdf(s) => s.finish(),
+            Self::Eps(s) => s.finish(),
+        }
+        Ok(())
+    }
+}
+
 #[derive(Parser, Debug)]
 struct Args {
     #[clap(long, value_parser = Size::from_arg, default_value_t = Size::new(3456,  2234))]
@@ -46,28 +107,188 @@ struct Args {
     #[clap(long, default_value = "wallpaper.png")]
     dst: String,
 
+    #[clap(long, value_parser = parse_paint, default_value = "solid:236cff")]
+    bg: Paint,
+
+    #[clap(long, value_parser = parse_paint, default_value = "solid:ffffff")]
+    fg: Paint,
+
+    /// Named color palette (intuit/sunset/mono). When given, overrides
+    /// --bg with a gradient ramped across the palette and --fg with one of
+    /// its anchors, so the background and logos stay color-coordinated.
+    #[clap(long)]
+    palette: Option<String>,
+
+    /// Custom logo artwork, imported from an SVG file's `d` path data.
+    /// Replaces the default twin-logo composition with this single mark.
+    #[clap(long)]
+    logo: Option<String>,
+
+    /// Render a soft drop-shadow behind each logo.
+    #[clap(long, default_value_t = false)]
+    shadow: bool,
+
+    #[clap(long, default_value_t = 24)]
+    shadow_radius: i32,
+
+    #[clap(long, default_value_t = 12.0)]
+    shadow_dx: f64,
+
+    #[clap(long, default_value_t = 12.0)]
+    shadow_dy: f64,
+
+    #[clap(long, default_value_t = 0.5)]
+    shadow_alpha: f64,
+
+    /// Tagline rendered centered beneath the logos, word-wrapped to fit.
+    #[clap(long)]
+    text: Option<String>,
+
+    #[clap(long, default_value = "sans-serif")]
+    font: String,
+
+    #[clap(long, default_value_t = 48.0)]
+    font_size: f64,
+
     #[clap(long, default_value_t = false)]
     debug: bool,
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let args = Args::parse();
-
-    let surface = ImageSurface::create(Format::ARgb32, args.size.width, args.size.height)?;
-    let ctx = Context::new(&surface)?;
+/// Vertical gap between the logos and the tagline, as a multiple of the
+/// tagline's font size.
+const TEXT_GAP_SCALE: f64 = 0.6;
 
-    let w = args.size.width as f64;
-    let h = args.size.height as f64;
+#[allow(clippy::too_many_arguments)]
+fn render(
+    ctx: &Context,
+    size: Size,
+    bg: &Paint,
+    fg: &Paint,
+    logo: Option<&path::Path>,
+    shadow: Option<&Shadow>,
+    text: Option<&str>,
+    font: &str,
+    font_size: f64,
+    debug: bool,
+) -> Result<(), Box<dyn Error>> {
+    let w = size.width as f64;
+    let h = size.height as f64;
 
     let cx = w / 2.0;
     let cy = h / 2.0;
 
     ctx.save()?;
-    Color::from_rgb(35, 108, 255).set(&ctx);
+    bg.set(ctx, &Rect::new((0.0, 0.0), (w, h)));
     ctx.rectangle(0.0, 0.0, w, h);
     ctx.fill()?;
     ctx.restore()?;
 
+    match logo {
+        Some(custom) => {
+            render_custom_logo(ctx, cx, cy, fg, custom, shadow, text, font, font_size, debug)?
+        }
+        None => {
+            render_default_logos(ctx, cx, cy, size, fg, shadow, text, font, font_size, debug)?
+        }
+    }
+
+    Ok(())
+}
+
+/// Wraps `text`, if given, against `max_width` (but never narrower than
+/// eight times `font_size`), selecting `font`/`font_size` on `ctx` first
+/// since the wrapper measures with whatever font is active.
+fn layout_text(
+    ctx: &Context,
+    text: Option<&str>,
+    font: &str,
+    font_size: f64,
+    max_width: f64,
+) -> Result<Option<Block>, Box<dyn Error>> {
+    let Some(text) = text else {
+        return Ok(None);
+    };
+    ctx.select_font_face(font, FontSlant::Normal, FontWeight::Normal);
+    ctx.set_font_size(font_size);
+    Ok(Some(Block::wrap(ctx, text, max_width.max(font_size * 8.0))?))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_custom_logo(
+    ctx: &Context,
+    cx: f64,
+    cy: f64,
+    fg: &Paint,
+    logo: &path::Path,
+    shadow: Option<&Shadow>,
+    text: Option<&str>,
+    font: &str,
+    font_size: f64,
+    debug: bool,
+) -> Result<(), Box<dyn Error>> {
+    let bounds = logo.bounds();
+    let (bounds_cx, bounds_cy) = bounds.center();
+
+    let block = layout_text(ctx, text, font, font_size, bounds.width())?;
+    let gap = font_size * TEXT_GAP_SCALE;
+    let total_h = bounds.height() + block.as_ref().map_or(0.0, |b| gap + b.height());
+    let top = cy - total_h / 2.0;
+    let logo_cy = top + bounds.height() / 2.0;
+    let (logo_tx, logo_ty) = (cx - bounds_cx, logo_cy - bounds_cy);
+
+    if debug {
+        ctx.save()?;
+        Color::from_u32(0xffffff).set(ctx);
+        ctx.translate(logo_tx, logo_ty);
+        ctx.rectangle(
+            bounds.top_left().0,
+            bounds.top_left().1,
+            bounds.width(),
+            bounds.height(),
+        );
+        ctx.stroke()?;
+        ctx.restore()?;
+    }
+
+    if let Some(shadow) = shadow {
+        shadow.paint(ctx, &bounds, 1.0, logo_tx, logo_ty, |sctx| logo.create(sctx))?;
+    }
+
+    ctx.save()?;
+    ctx.translate(logo_tx, logo_ty);
+    fg.set(ctx, &bounds);
+    logo.create(ctx);
+    ctx.fill()?;
+    ctx.restore()?;
+
+    if let Some(block) = &block {
+        let text_top = top + bounds.height() + gap;
+        fg.set(
+            ctx,
+            &Rect::new(
+                (cx - block.width() / 2.0, text_top),
+                (cx + block.width() / 2.0, text_top + block.height()),
+            ),
+        );
+        block.draw(ctx, cx, text_top)?;
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_default_logos(
+    ctx: &Context,
+    cx: f64,
+    cy: f64,
+    size: Size,
+    fg: &Paint,
+    shadow: Option<&Shadow>,
+    text: Option<&str>,
+    font: &str,
+    font_size: f64,
+    debug: bool,
+) -> Result<(), Box<dyn Error>> {
     let is = 1.2;
     let es = 0.8;
 
@@ -76,44 +297,114 @@ fn main() -> Result<(), Box<dyn Error>> {
     let th = ir.height().max(er.height());
     let tw = ir.width() + er.width() + er.width() * 0.25;
 
-    if args.debug {
+    let block = layout_text(ctx, text, font, font_size, tw)?;
+    let gap = font_size * TEXT_GAP_SCALE;
+    let total_h = th + block.as_ref().map_or(0.0, |b| gap + b.height());
+    let top = cy - total_h / 2.0;
+    let logo_cy = top + th / 2.0;
+
+    if debug {
         ctx.save()?;
-        Color::from_u32(0xffffff).set(&ctx);
-        ctx.translate(cx, cy);
+        Color::from_u32(0xffffff).set(ctx);
+        ctx.translate(cx, logo_cy);
         ctx.rectangle(-tw / 2.0, -th / 2.0, tw, th);
         ctx.stroke()?;
         ctx.restore()?;
     }
 
+    let b_tx = cx - tw / 2.0 + er.width() / 2.0;
+    let a_tx = cx + er.width() / 2.0 + er.width() * 0.125;
+
+    if let Some(shadow) = shadow {
+        shadow.paint(ctx, &logo_b::bounds(), es, b_tx, logo_cy, logo_b::create)?;
+        shadow.paint(ctx, &logo_a::bounds(), is, a_tx, logo_cy, logo_a::create)?;
+    }
+
     ctx.save()?;
-    Color::from_u32(0xffffff).set(&ctx);
-    ctx.translate(cx - tw / 2.0 + er.width() / 2.0, cy);
+    ctx.translate(b_tx, logo_cy);
     ctx.scale(es, es);
-    logo_b::create(&ctx);
+    fg.set(ctx, &logo_b::bounds());
+    logo_b::create(ctx);
     ctx.fill()?;
     ctx.restore()?;
 
     ctx.save()?;
-    Color::from_u32(0xffffff).set(&ctx);
-    ctx.translate(cx + er.width() / 2.0 + er.width() * 0.125, cy);
+    ctx.translate(a_tx, logo_cy);
     ctx.scale(is, is);
-    logo_a::create(&ctx);
+    fg.set(ctx, &logo_a::bounds());
+    logo_a::create(ctx);
     ctx.fill()?;
     ctx.restore()?;
 
-    if args.debug {
+    if let Some(block) = &block {
+        let text_top = top + th + gap;
+        fg.set(
+            ctx,
+            &Rect::new(
+                (cx - block.width() / 2.0, text_top),
+                (cx + block.width() / 2.0, text_top + block.height()),
+            ),
+        );
+        block.draw(ctx, cx, text_top)?;
+    }
+
+    if debug {
         ctx.save()?;
-        Color::from_u32(0xffffff).set(&ctx);
+        Color::from_u32(0xffffff).set(ctx);
         ctx.new_path();
         ctx.move_to(0.0, cy);
-        ctx.line_to(args.size.width as f64, cy);
+        ctx.line_to(size.width as f64, cy);
         ctx.move_to(cx, 0.0);
-        ctx.line_to(cx, args.size.height as f64);
+        ctx.line_to(cx, size.height as f64);
         ctx.stroke()?;
         ctx.restore()?;
     }
 
-    surface.write_to_png(&mut fs::File::create(&args.dst)?)?;
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+
+    let logo = args.logo.as_deref().map(path::Path::load_svg).transpose()?;
+    let shadow = args.shadow.then_some(Shadow {
+        radius: args.shadow_radius,
+        dx: args.shadow_dx,
+        dy: args.shadow_dy,
+        alpha: args.shadow_alpha,
+    });
+
+    let (bg, fg) = match &args.palette {
+        Some(name) => {
+            let anchors = palette::named(name)
+                .ok_or_else(|| format!("unknown palette: {} (expected intuit, sunset, mono)", name))?;
+            let bg = Paint::Linear {
+                stops: palette::ramp_stops(&anchors, 5),
+                angle: 45.0,
+            };
+            let fg = Paint::Solid(*anchors.last().expect("palette has at least one anchor"));
+            (bg, fg)
+        }
+        None => (args.bg.clone(), args.fg.clone()),
+    };
+
+    let surface = Surface::create(&args.dst, &args.size)?;
+    let ctx = surface.context()?;
+
+    render(
+        &ctx,
+        args.size.clone(),
+        &bg,
+        &fg,
+        logo.as_ref(),
+        shadow.as_ref(),
+        args.text.as_deref(),
+        &args.font,
+        args.font_size,
+        args.debug,
+    )?;
+
+    surface.finish(&args.dst)?;
 
     Ok(())
 }
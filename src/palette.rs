@@ -0,0 +1,106 @@
+//! Evenly spaced color ramps and a handful of named palettes, built by
+//! interpolating between anchor colors in HSV space, the way Asymptote's
+//! `palette.asy` builds its color ramps.
+
+use crate::{Color, Stop};
+
+/// Produces `n` colors evenly spaced along the ramp formed by `anchors`,
+/// interpolating hue/saturation/value between each consecutive pair.
+pub fn ramp(anchors: &[Color], n: usize) -> Vec<Color> {
+    match anchors.len() {
+        0 => Vec::new(),
+        1 => vec![anchors[0]; n],
+        _ if n == 0 => Vec::new(),
+        _ if n == 1 => vec![anchors[0]],
+        _ => {
+            let segments = anchors.len() - 1;
+            (0..n)
+                .map(|i| {
+                    let t = i as f64 / (n - 1) as f64 * segments as f64;
+                    let seg = (t.floor() as usize).min(segments - 1);
+                    lerp_hsv(anchors[seg], anchors[seg + 1], t - seg as f64)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Same as [`ramp`], but as gradient [`Stop`]s evenly spaced over `[0, 1]`
+/// with full alpha, ready to drop into a [`crate::Paint::Linear`] or
+/// [`crate::Paint::Radial`].
+pub fn ramp_stops(anchors: &[Color], n: usize) -> Vec<Stop> {
+    let colors = ramp(anchors, n);
+    let last = colors.len().saturating_sub(1).max(1) as f64;
+    colors
+        .into_iter()
+        .enumerate()
+        .map(|(i, color)| Stop::new(i as f64 / last, color, 1.0))
+        .collect()
+}
+
+fn lerp_hsv(a: Color, b: Color, t: f64) -> Color {
+    let (h0, s0, v0) = a.to_hsv();
+    let (h1, s1, v1) = b.to_hsv();
+
+    let dh = {
+        let d = h1 - h0;
+        if d.abs() > 180.0 {
+            if d > 0.0 {
+                d - 360.0
+            } else {
+                d + 360.0
+            }
+        } else {
+            d
+        }
+    };
+
+    Color::from_hsv(
+        (h0 + dh * t).rem_euclid(360.0),
+        s0 + (s1 - s0) * t,
+        v0 + (v1 - v0) * t,
+    )
+}
+
+/// Anchor colors for a built-in, named palette.
+pub fn named(name: &str) -> Option<Vec<Color>> {
+    match name {
+        "intuit" => Some(vec![
+            Color::from_u32(0x236cff),
+            Color::from_u32(0x0a3d2c),
+            Color::from_u32(0xffffff),
+        ]),
+        "sunset" => Some(vec![
+            Color::from_u32(0x2c1e4a),
+            Color::from_u32(0xff6b35),
+            Color::from_u32(0xffd37f),
+        ]),
+        "mono" => Some(vec![Color::from_u32(0x111111), Color::from_u32(0xffffff)]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ramp_starts_and_ends_on_anchors() {
+        let anchors = vec![Color::from_u32(0x000000), Color::from_u32(0xffffff)];
+        let colors = ramp(&anchors, 5);
+        assert_eq!(colors.len(), 5);
+        assert_eq!((colors[0].r(), colors[0].g(), colors[0].b()), (0, 0, 0));
+        assert_eq!(
+            (colors[4].r(), colors[4].g(), colors[4].b()),
+            (255, 255, 255)
+        );
+    }
+
+    #[test]
+    fn ramp_stops_span_zero_to_one() {
+        let anchors = vec![Color::from_u32(0x000000), Color::from_u32(0xffffff)];
+        let stops = ramp_stops(&anchors, 3);
+        assert_eq!(stops.first().unwrap().offset, 0.0);
+        assert_eq!(stops.last().unwrap().offset, 1.0);
+    }
+}
@@ -0,0 +1,518 @@
+//! Imports logo artwork from SVG path data, so `--logo` isn't limited to the
+//! hand-transcribed shapes in [`crate::logo_a`]/[`crate::logo_b`].
+
+use crate::Rect;
+use cairo::Context;
+use std::error::Error;
+use std::f64::consts::PI;
+
+/// A path parsed from an SVG `d` attribute, ready to replay against a
+/// cairo `Context` the same way [`crate::logo_a::create`] does.
+pub struct Path {
+    ops: Vec<Op>,
+    bounds: Rect,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    MoveTo(f64, f64),
+    LineTo(f64, f64),
+    CurveTo(f64, f64, f64, f64, f64, f64),
+    ClosePath,
+}
+
+impl Path {
+    /// Reads the first `d="..."` path attribute out of an SVG file and
+    /// parses it. This is not a general SVG parser: it only looks for a
+    /// single double-quoted `d` attribute, which is all the flat, single-path
+    /// icons this tool composes need.
+    pub fn load_svg(path: &str) -> Result<Self, Box<dyn Error>> {
+        let svg = std::fs::read_to_string(path)?;
+        let d = extract_d(&svg).ok_or("no path data (d=\"...\") found in SVG")?;
+        Self::parse(d)
+    }
+
+    /// Parses an SVG path `d` string (`M/m L/l H/h V/v C/c S/s Q/q T/t A/a
+    /// Z/z`) into the absolute `move_to`/`line_to`/`curve_to` calls cairo
+    /// understands. Quadratic and smooth curves are converted to cubic
+    /// béziers and elliptical arcs are split into cubic bézier segments of
+    /// at most 90° each.
+    pub fn parse(d: &str) -> Result<Self, Box<dyn Error>> {
+        let mut tokens = Tokenizer::new(d);
+        let mut ops = Vec::new();
+
+        let mut cur = (0.0, 0.0);
+        let mut start = (0.0, 0.0);
+        let mut prev_cubic_ctrl: Option<(f64, f64)> = None;
+        let mut prev_quad_ctrl: Option<(f64, f64)> = None;
+        let mut cmd = tokens.next_command().ok_or("empty path")?;
+
+        loop {
+            let relative = cmd.is_ascii_lowercase();
+            match cmd.to_ascii_uppercase() {
+                'M' => {
+                    let p = tokens.point(cur, relative)?;
+                    cur = p;
+                    start = p;
+                    ops.push(Op::MoveTo(p.0, p.1));
+                    prev_cubic_ctrl = None;
+                    prev_quad_ctrl = None;
+                    // A moveto's subsequent coordinate pairs are implicit linetos.
+                    cmd = if relative { 'l' } else { 'L' };
+                }
+                'L' => {
+                    let p = tokens.point(cur, relative)?;
+                    cur = p;
+                    ops.push(Op::LineTo(p.0, p.1));
+                    prev_cubic_ctrl = None;
+                    prev_quad_ctrl = None;
+                }
+                'H' => {
+                    let x = tokens.number()?;
+                    cur = (if relative { cur.0 + x } else { x }, cur.1);
+                    ops.push(Op::LineTo(cur.0, cur.1));
+                    prev_cubic_ctrl = None;
+                    prev_quad_ctrl = None;
+                }
+                'V' => {
+                    let y = tokens.number()?;
+                    cur = (cur.0, if relative { cur.1 + y } else { y });
+                    ops.push(Op::LineTo(cur.0, cur.1));
+                    prev_cubic_ctrl = None;
+                    prev_quad_ctrl = None;
+                }
+                'C' => {
+                    let c1 = tokens.point(cur, relative)?;
+                    let c2 = tokens.point(cur, relative)?;
+                    let p = tokens.point(cur, relative)?;
+                    ops.push(Op::CurveTo(c1.0, c1.1, c2.0, c2.1, p.0, p.1));
+                    prev_cubic_ctrl = Some(c2);
+                    prev_quad_ctrl = None;
+                    cur = p;
+                }
+                'S' => {
+                    let c1 = reflect(cur, prev_cubic_ctrl);
+                    let c2 = tokens.point(cur, relative)?;
+                    let p = tokens.point(cur, relative)?;
+                    ops.push(Op::CurveTo(c1.0, c1.1, c2.0, c2.1, p.0, p.1));
+                    prev_cubic_ctrl = Some(c2);
+                    prev_quad_ctrl = None;
+                    cur = p;
+                }
+                'Q' => {
+                    let ctrl = tokens.point(cur, relative)?;
+                    let p = tokens.point(cur, relative)?;
+                    let (c1, c2) = quad_to_cubic(cur, ctrl, p);
+                    ops.push(Op::CurveTo(c1.0, c1.1, c2.0, c2.1, p.0, p.1));
+                    prev_cubic_ctrl = None;
+                    prev_quad_ctrl = Some(ctrl);
+                    cur = p;
+                }
+                'T' => {
+                    let ctrl = reflect(cur, prev_quad_ctrl);
+                    let p = tokens.point(cur, relative)?;
+                    let (c1, c2) = quad_to_cubic(cur, ctrl, p);
+                    ops.push(Op::CurveTo(c1.0, c1.1, c2.0, c2.1, p.0, p.1));
+                    prev_cubic_ctrl = None;
+                    prev_quad_ctrl = Some(ctrl);
+                    cur = p;
+                }
+                'A' => {
+                    let rx = tokens.number()?;
+                    let ry = tokens.number()?;
+                    let x_rot = tokens.number()?;
+                    let large_arc = tokens.flag()?;
+                    let sweep = tokens.flag()?;
+                    let p = tokens.point(cur, relative)?;
+                    arc_to_cubics(cur, rx, ry, x_rot, large_arc, sweep, p, &mut ops);
+                    prev_cubic_ctrl = None;
+                    prev_quad_ctrl = None;
+                    cur = p;
+                }
+                'Z' => {
+                    ops.push(Op::ClosePath);
+                    cur = start;
+                    prev_cubic_ctrl = None;
+                    prev_quad_ctrl = None;
+                }
+                other => return Err(format!("unsupported path command: {}", other).into()),
+            }
+
+            match tokens.next_command_or_repeat(cmd)? {
+                Some(next) => cmd = next,
+                None => break,
+            }
+        }
+
+        let bounds = bounds_of(&ops);
+        Ok(Self { ops, bounds })
+    }
+
+    pub fn create(&self, ctx: &Context) {
+        ctx.new_path();
+        for op in &self.ops {
+            match *op {
+                Op::MoveTo(x, y) => ctx.move_to(x, y),
+                Op::LineTo(x, y) => ctx.line_to(x, y),
+                Op::CurveTo(x1, y1, x2, y2, x3, y3) => ctx.curve_to(x1, y1, x2, y2, x3, y3),
+                Op::ClosePath => ctx.close_path(),
+            }
+        }
+    }
+
+    pub fn bounds(&self) -> Rect {
+        self.bounds.clone()
+    }
+}
+
+/// Finds the first `d="..."` *attribute* (not e.g. the `d` inside
+/// `id="..."`) by requiring the `d` to be preceded by an attribute
+/// boundary (whitespace) rather than matching the bare substring.
+fn extract_d(svg: &str) -> Option<String> {
+    let key = "d=\"";
+    let mut search_from = 0;
+    loop {
+        let rel = svg[search_from..].find(key)?;
+        let at = search_from + rel;
+        let preceded_by_boundary = svg[..at]
+            .chars()
+            .next_back()
+            .map_or(true, |c| c.is_whitespace());
+        if preceded_by_boundary {
+            let start = at + key.len();
+            let end = svg[start..].find('"')? + start;
+            return Some(svg[start..end].to_string());
+        }
+        search_from = at + key.len();
+    }
+}
+
+fn reflect(p: (f64, f64), ctrl: Option<(f64, f64)>) -> (f64, f64) {
+    match ctrl {
+        Some(c) => (2.0 * p.0 - c.0, 2.0 * p.1 - c.1),
+        None => p,
+    }
+}
+
+fn quad_to_cubic(p0: (f64, f64), ctrl: (f64, f64), p1: (f64, f64)) -> ((f64, f64), (f64, f64)) {
+    let c1 = (
+        p0.0 + 2.0 / 3.0 * (ctrl.0 - p0.0),
+        p0.1 + 2.0 / 3.0 * (ctrl.1 - p0.1),
+    );
+    let c2 = (
+        p1.0 + 2.0 / 3.0 * (ctrl.0 - p1.0),
+        p1.1 + 2.0 / 3.0 * (ctrl.1 - p1.1),
+    );
+    (c1, c2)
+}
+
+/// Converts an SVG elliptical arc to a sequence of cubic béziers using the
+/// standard center parameterization (SVG 1.1 appendix F.6), splitting the
+/// arc into segments of at most 90°.
+fn arc_to_cubics(
+    p0: (f64, f64),
+    rx: f64,
+    ry: f64,
+    x_rot_deg: f64,
+    large_arc: bool,
+    sweep: bool,
+    p1: (f64, f64),
+    ops: &mut Vec<Op>,
+) {
+    if p0 == p1 {
+        return;
+    }
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+    if rx == 0.0 || ry == 0.0 {
+        ops.push(Op::LineTo(p1.0, p1.1));
+        return;
+    }
+
+    let phi = x_rot_deg.to_radians();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+
+    let dx2 = (p0.0 - p1.0) / 2.0;
+    let dy2 = (p0.1 - p1.1) / 2.0;
+    let x1p = cos_phi * dx2 + sin_phi * dy2;
+    let y1p = -sin_phi * dx2 + cos_phi * dy2;
+
+    let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+    if lambda > 1.0 {
+        let s = lambda.sqrt();
+        rx *= s;
+        ry *= s;
+    }
+
+    let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+    let num = (rx * rx * ry * ry - rx * rx * y1p * y1p - ry * ry * x1p * x1p).max(0.0);
+    let denom = rx * rx * y1p * y1p + ry * ry * x1p * x1p;
+    let coef = sign * (num / denom).sqrt();
+    let cxp = coef * (rx * y1p / ry);
+    let cyp = coef * -(ry * x1p / rx);
+
+    let cx = cos_phi * cxp - sin_phi * cyp + (p0.0 + p1.0) / 2.0;
+    let cy = sin_phi * cxp + cos_phi * cyp + (p0.1 + p1.1) / 2.0;
+
+    let angle_between = |ux: f64, uy: f64, vx: f64, vy: f64| -> f64 {
+        let dot = ux * vx + uy * vy;
+        let len = (ux * ux + uy * uy).sqrt() * (vx * vx + vy * vy).sqrt();
+        let mut a = (dot / len).clamp(-1.0, 1.0).acos();
+        if ux * vy - uy * vx < 0.0 {
+            a = -a;
+        }
+        a
+    };
+
+    let theta1 = angle_between(1.0, 0.0, (x1p - cxp) / rx, (y1p - cyp) / ry);
+    let mut dtheta = angle_between(
+        (x1p - cxp) / rx,
+        (y1p - cyp) / ry,
+        (-x1p - cxp) / rx,
+        (-y1p - cyp) / ry,
+    );
+    if !sweep && dtheta > 0.0 {
+        dtheta -= 2.0 * PI;
+    } else if sweep && dtheta < 0.0 {
+        dtheta += 2.0 * PI;
+    }
+
+    let segments = (dtheta.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+    let delta = dtheta / segments as f64;
+    let kappa = 4.0 / 3.0 * (delta / 4.0).tan();
+
+    let mut theta = theta1;
+    for _ in 0..segments {
+        let theta_next = theta + delta;
+
+        let (sin_t, cos_t) = theta.sin_cos();
+        let (sin_tn, cos_tn) = theta_next.sin_cos();
+
+        let p_start = (
+            cx + rx * cos_phi * cos_t - ry * sin_phi * sin_t,
+            cy + rx * sin_phi * cos_t + ry * cos_phi * sin_t,
+        );
+        let p_end = (
+            cx + rx * cos_phi * cos_tn - ry * sin_phi * sin_tn,
+            cy + rx * sin_phi * cos_tn + ry * cos_phi * sin_tn,
+        );
+
+        let d_start = (
+            -rx * cos_phi * sin_t - ry * sin_phi * cos_t,
+            -rx * sin_phi * sin_t + ry * cos_phi * cos_t,
+        );
+        let d_end = (
+            -rx * cos_phi * sin_tn - ry * sin_phi * cos_tn,
+            -rx * sin_phi * sin_tn + ry * cos_phi * cos_tn,
+        );
+
+        let c1 = (p_start.0 + kappa * d_start.0, p_start.1 + kappa * d_start.1);
+        let c2 = (p_end.0 - kappa * d_end.0, p_end.1 - kappa * d_end.1);
+
+        ops.push(Op::CurveTo(c1.0, c1.1, c2.0, c2.1, p_end.0, p_end.1));
+
+        theta = theta_next;
+    }
+}
+
+/// Computes the bounding box of `ops`, flattening curves by sampling, so
+/// `main` can lay out a custom logo by its `Rect` the same way it lays out
+/// `logo_a::bounds()`/`logo_b::bounds()`.
+fn bounds_of(ops: &[Op]) -> Rect {
+    const STEPS: usize = 32;
+
+    let mut min = (f64::INFINITY, f64::INFINITY);
+    let mut max = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    let mut cur = (0.0, 0.0);
+    let mut start = (0.0, 0.0);
+
+    for op in ops {
+        match *op {
+            Op::MoveTo(x, y) => {
+                cur = (x, y);
+                start = cur;
+                include(cur, &mut min, &mut max);
+            }
+            Op::LineTo(x, y) => {
+                cur = (x, y);
+                include(cur, &mut min, &mut max);
+            }
+            Op::CurveTo(x1, y1, x2, y2, x3, y3) => {
+                for i in 0..=STEPS {
+                    let t = i as f64 / STEPS as f64;
+                    include(
+                        cubic_point(cur, (x1, y1), (x2, y2), (x3, y3), t),
+                        &mut min,
+                        &mut max,
+                    );
+                }
+                cur = (x3, y3);
+            }
+            Op::ClosePath => cur = start,
+        }
+    }
+
+    if !min.0.is_finite() {
+        min = (0.0, 0.0);
+        max = (0.0, 0.0);
+    }
+
+    Rect::new(min, max)
+}
+
+fn include(p: (f64, f64), min: &mut (f64, f64), max: &mut (f64, f64)) {
+    min.0 = min.0.min(p.0);
+    min.1 = min.1.min(p.1);
+    max.0 = max.0.max(p.0);
+    max.1 = max.1.max(p.1);
+}
+
+fn cubic_point(
+    p0: (f64, f64),
+    p1: (f64, f64),
+    p2: (f64, f64),
+    p3: (f64, f64),
+    t: f64,
+) -> (f64, f64) {
+    let mt = 1.0 - t;
+    (
+        mt * mt * mt * p0.0 + 3.0 * mt * mt * t * p1.0 + 3.0 * mt * t * t * p2.0 + t * t * t * p3.0,
+        mt * mt * mt * p0.1 + 3.0 * mt * mt * t * p1.1 + 3.0 * mt * t * t * p2.1 + t * t * t * p3.1,
+    )
+}
+
+struct Tokenizer<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(s: &'a str) -> Self {
+        Self {
+            chars: s.chars().peekable(),
+        }
+    }
+
+    fn skip_sep(&mut self) {
+        while matches!(self.chars.peek(), Some(c) if c.is_whitespace() || *c == ',') {
+            self.chars.next();
+        }
+    }
+
+    fn next_command(&mut self) -> Option<char> {
+        self.skip_sep();
+        match self.chars.peek() {
+            Some(c) if c.is_ascii_alphabetic() => self.chars.next(),
+            _ => None,
+        }
+    }
+
+    fn next_command_or_repeat(&mut self, prev: char) -> Result<Option<char>, Box<dyn Error>> {
+        self.skip_sep();
+        match self.chars.peek() {
+            None => Ok(None),
+            Some(c) if c.is_ascii_alphabetic() => Ok(self.chars.next()),
+            Some(_) if prev.to_ascii_uppercase() == 'Z' => {
+                Err("unexpected number after closepath".into())
+            }
+            Some(_) => Ok(Some(prev)),
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, Box<dyn Error>> {
+        self.skip_sep();
+        let mut s = String::new();
+        if matches!(self.chars.peek(), Some('+') | Some('-')) {
+            s.push(self.chars.next().unwrap());
+        }
+        let mut seen_dot = false;
+        while let Some(&c) = self.chars.peek() {
+            if c.is_ascii_digit() {
+                s.push(c);
+                self.chars.next();
+            } else if c == '.' && !seen_dot {
+                seen_dot = true;
+                s.push(c);
+                self.chars.next();
+            } else if (c == 'e' || c == 'E') && !s.is_empty() {
+                s.push(c);
+                self.chars.next();
+                if matches!(self.chars.peek(), Some('+') | Some('-')) {
+                    s.push(self.chars.next().unwrap());
+                }
+            } else {
+                break;
+            }
+        }
+        if s.is_empty() || s == "-" || s == "+" {
+            return Err("expected number in path data".into());
+        }
+        Ok(s.parse()?)
+    }
+
+    fn point(&mut self, cur: (f64, f64), relative: bool) -> Result<(f64, f64), Box<dyn Error>> {
+        let x = self.number()?;
+        let y = self.number()?;
+        Ok(if relative {
+            (cur.0 + x, cur.1 + y)
+        } else {
+            (x, y)
+        })
+    }
+
+    fn flag(&mut self) -> Result<bool, Box<dyn Error>> {
+        self.skip_sep();
+        match self.chars.next() {
+            Some('0') => Ok(false),
+            Some('1') => Ok(true),
+            _ => Err("expected flag (0 or 1) in path data".into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quad_to_cubic_places_controls_two_thirds_toward_ctrl() {
+        let (c1, c2) = quad_to_cubic((0.0, 0.0), (50.0, 100.0), (100.0, 0.0));
+        assert!((c1.0 - 33.333333).abs() < 1e-6);
+        assert!((c1.1 - 66.666667).abs() < 1e-6);
+        assert!((c2.0 - 66.666667).abs() < 1e-6);
+        assert!((c2.1 - 66.666667).abs() < 1e-6);
+    }
+
+    #[test]
+    fn arc_to_cubics_semicircle_spans_full_diameter() {
+        let mut ops = vec![Op::MoveTo(100.0, 0.0)];
+        arc_to_cubics((100.0, 0.0), 100.0, 100.0, 0.0, true, true, (-100.0, 0.0), &mut ops);
+        let bounds = bounds_of(&ops);
+        assert!((bounds.width() - 200.0).abs() < 1.0);
+        assert!((bounds.height() - 100.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn bounds_of_straight_line() {
+        let ops = vec![Op::MoveTo(0.0, 0.0), Op::LineTo(10.0, 20.0)];
+        let bounds = bounds_of(&ops);
+        assert_eq!(*bounds.top_left(), (0.0, 0.0));
+        assert_eq!(*bounds.bottom_right(), (10.0, 20.0));
+    }
+
+    #[test]
+    fn bounds_of_empty_defaults_to_origin() {
+        let bounds = bounds_of(&[]);
+        assert_eq!(*bounds.top_left(), (0.0, 0.0));
+        assert_eq!(*bounds.bottom_right(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn closepath_rejects_trailing_number() {
+        assert!(Path::parse("M0 0 L10 10 Z 2 2").is_err());
+    }
+
+    #[test]
+    fn extract_d_skips_id_attribute() {
+        let svg = r#"<svg><path id="logo" d="M0 0 L10 10 Z"/></svg>"#;
+        assert_eq!(extract_d(svg).as_deref(), Some("M0 0 L10 10 Z"));
+    }
+}
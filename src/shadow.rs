@@ -0,0 +1,157 @@
+//! Soft drop-shadows for logo artwork: render the shape in black onto an
+//! offscreen surface, blur its alpha channel, and composite it under the
+//! real fill for depth.
+
+use crate::{Color, Rect};
+use cairo::{Context, Format, ImageSurface};
+use std::error::Error;
+
+/// A drop-shadow recipe, applied once per logo before its real fill is
+/// painted.
+#[derive(Debug, Clone, Copy)]
+pub struct Shadow {
+    pub radius: i32,
+    pub dx: f64,
+    pub dy: f64,
+    pub alpha: f64,
+}
+
+impl Shadow {
+    /// Renders `draw`'s path (in `local_bounds`, scaled by `scale` and
+    /// positioned at `(tx, ty)` the same way the real fill will be) as a
+    /// blurred black silhouette and composites it onto `ctx`.
+    ///
+    /// `ctx` must be untransformed (identity CTM) when this is called, since
+    /// `(tx, ty)` and `scale` already carry the placement the caller would
+    /// otherwise have expressed as `ctx.translate`/`ctx.scale`.
+    pub fn paint(
+        &self,
+        ctx: &Context,
+        local_bounds: &Rect,
+        scale: f64,
+        tx: f64,
+        ty: f64,
+        draw: impl Fn(&Context),
+    ) -> Result<(), Box<dyn Error>> {
+        let margin = self.radius.max(0) as f64;
+        let w = (local_bounds.width() * scale + margin * 2.0).ceil().max(1.0) as i32;
+        let h = (local_bounds.height() * scale + margin * 2.0).ceil().max(1.0) as i32;
+
+        let mut surface = ImageSurface::create(Format::ARgb32, w, h)?;
+        {
+            let sctx = Context::new(&surface)?;
+            sctx.translate(
+                margin - local_bounds.top_left().0 * scale,
+                margin - local_bounds.top_left().1 * scale,
+            );
+            sctx.scale(scale, scale);
+            Color::from_u32(0x000000).set(&sctx);
+            draw(&sctx);
+            sctx.fill()?;
+        }
+
+        if self.radius > 0 {
+            box_blur(&mut surface, self.radius as usize)?;
+        }
+
+        let device_x = tx + local_bounds.top_left().0 * scale;
+        let device_y = ty + local_bounds.top_left().1 * scale;
+
+        ctx.save()?;
+        ctx.set_source_surface(&surface, device_x - margin + self.dx, device_y - margin + self.dy)?;
+        ctx.paint_with_alpha(self.alpha)?;
+        ctx.restore()?;
+
+        Ok(())
+    }
+}
+
+/// A separable box blur run three times, which approximates a Gaussian of
+/// sigma = `sqrt(radius * (radius + 1))`, i.e. roughly `radius`.
+fn box_blur(surface: &mut ImageSurface, radius: usize) -> Result<(), Box<dyn Error>> {
+    let width = surface.width() as usize;
+    let height = surface.height() as usize;
+    let stride = surface.stride() as usize;
+    let mut data = surface.data()?;
+
+    for _ in 0..3 {
+        blur_rows(&mut data, width, height, stride, radius);
+        blur_columns(&mut data, width, height, stride, radius);
+    }
+
+    Ok(())
+}
+
+/// Slides a `2*radius+1`-wide window across each row, maintaining a running
+/// sum of premultiplied ARGB bytes per channel, clamping at the row edges.
+fn blur_rows(data: &mut [u8], width: usize, height: usize, stride: usize, radius: usize) {
+    let window = (2 * radius + 1) as u32;
+    let mut line = vec![0u8; width * 4];
+
+    for y in 0..height {
+        let row = y * stride;
+        line.copy_from_slice(&data[row..row + width * 4]);
+
+        for c in 0..4 {
+            let sample = |x: isize| -> u32 { line[clamp(x, width) * 4 + c] as u32 };
+
+            let mut sum: u32 = (-(radius as isize)..=radius as isize).map(sample).sum();
+            for x in 0..width {
+                data[row + x * 4 + c] = (sum / window) as u8;
+                sum += sample(x as isize + radius as isize + 1);
+                sum -= sample(x as isize - radius as isize);
+            }
+        }
+    }
+}
+
+/// Slides the same window down each column.
+fn blur_columns(data: &mut [u8], width: usize, height: usize, stride: usize, radius: usize) {
+    let window = (2 * radius + 1) as u32;
+    let mut line = vec![0u8; height * 4];
+
+    for x in 0..width {
+        for y in 0..height {
+            let px = y * stride + x * 4;
+            line[y * 4..y * 4 + 4].copy_from_slice(&data[px..px + 4]);
+        }
+
+        for c in 0..4 {
+            let sample = |y: isize| -> u32 { line[clamp(y, height) * 4 + c] as u32 };
+
+            let mut sum: u32 = (-(radius as isize)..=radius as isize).map(sample).sum();
+            for y in 0..height {
+                data[y * stride + x * 4 + c] = (sum / window) as u8;
+                sum += sample(y as isize + radius as isize + 1);
+                sum -= sample(y as isize - radius as isize);
+            }
+        }
+    }
+}
+
+fn clamp(i: isize, len: usize) -> usize {
+    i.clamp(0, len as isize - 1) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn box_blur_of_constant_image_is_unchanged() {
+        let mut surface = ImageSurface::create(Format::ARgb32, 16, 16).unwrap();
+        {
+            let ctx = Context::new(&surface).unwrap();
+            ctx.set_source_rgba(0.2, 0.4, 0.6, 0.8);
+            ctx.paint().unwrap();
+        }
+
+        let before = surface.data().unwrap().to_vec();
+        box_blur(&mut surface, 3).unwrap();
+        let after = surface.data().unwrap();
+
+        for (b, a) in before.iter().zip(after.iter()) {
+            assert!((*b as i32 - *a as i32).abs() <= 1, "{} vs {}", b, a);
+        }
+    }
+}
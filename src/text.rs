@@ -0,0 +1,76 @@
+//! A centered, word-wrapped tagline rendered beneath the composed logos.
+
+use cairo::Context;
+use std::error::Error;
+
+/// Word-wrapped lines of text, measured against a font already selected on
+/// the `Context` used to build it.
+pub struct Block {
+    lines: Vec<String>,
+    line_height: f64,
+    width: f64,
+}
+
+impl Block {
+    /// Greedily packs `text`'s words onto lines no wider than `max_width`,
+    /// measuring each candidate line with `ctx.text_extents` the way ctx's
+    /// `ctx_wrap_left` measures word-by-word against a margin. A single word
+    /// wider than `max_width` is not split and gets a line of its own.
+    /// Callers must have already called `select_font_face`/`set_font_size`
+    /// on `ctx`.
+    pub fn wrap(ctx: &Context, text: &str, max_width: f64) -> Result<Self, Box<dyn Error>> {
+        let font = ctx.font_extents()?;
+        let mut lines = Vec::new();
+        let mut width = 0.0_f64;
+        let mut line = String::new();
+
+        for word in text.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", line, word)
+            };
+
+            if !line.is_empty() && ctx.text_extents(&candidate)?.width() > max_width {
+                width = width.max(ctx.text_extents(&line)?.width());
+                lines.push(std::mem::replace(&mut line, word.to_string()));
+            } else {
+                line = candidate;
+            }
+        }
+        if !line.is_empty() {
+            width = width.max(ctx.text_extents(&line)?.width());
+            lines.push(line);
+        }
+
+        Ok(Self {
+            lines,
+            line_height: font.height(),
+            width,
+        })
+    }
+
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    pub fn height(&self) -> f64 {
+        self.line_height * self.lines.len() as f64
+    }
+
+    /// Draws each line centered horizontally at `cx`, stacked downward
+    /// starting at `top`.
+    pub fn draw(&self, ctx: &Context, cx: f64, top: f64) -> Result<(), Box<dyn Error>> {
+        let font = ctx.font_extents()?;
+        let mut y = top + font.ascent();
+
+        for line in &self.lines {
+            let w = ctx.text_extents(line)?.width();
+            ctx.move_to(cx - w / 2.0, y);
+            ctx.show_text(line)?;
+            y += font.height();
+        }
+
+        Ok(())
+    }
+}